@@ -0,0 +1,68 @@
+// imports
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*imports][imports:1]]
+// imports:1 ends here
+
+// covalent radii
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*covalent radii][covalent radii:1]]
+/// Single-bond covalent radii in Angstrom, indexed by `atomic number - 1`.
+///
+/// Data taken from Cordero et al. Dalton Trans., 2008, 2832-2838. Used for
+/// distance based bond perception (see [rebond](struct.Molecule.html#method.rebond)).
+const COVALENT_RADII: [f64; 86] = [
+    0.31, 0.28, // H, He
+    1.28, 0.96, 0.84, 0.76, 0.71, 0.66, 0.57, 0.58, // Li .. Ne
+    1.66, 1.41, 1.21, 1.11, 1.07, 1.05, 1.02, 1.06, // Na .. Ar
+    2.03, 1.76, 1.70, 1.60, 1.53, 1.39, 1.39, 1.32, 1.26, 1.24, 1.32, 1.22, // K .. Zn
+    1.22, 1.20, 1.19, 1.20, 1.20, 1.16, // Ga .. Kr
+    2.20, 1.95, 1.90, 1.75, 1.64, 1.54, 1.47, 1.46, 1.42, 1.39, 1.45, 1.44, // Rb .. Cd
+    1.42, 1.39, 1.39, 1.38, 1.39, 1.40, // In .. Xe
+    2.44, 2.15, 2.07, 2.04, 2.03, 2.01, 1.99, 1.98, 1.98, 1.96, 1.94, 1.92, 1.92, 1.89, 1.90,
+    1.87, 1.87, // Cs .. Lu
+    1.75, 1.70, 1.62, 1.51, 1.44, 1.41, 1.36, 1.36, 1.32, // Hf .. Hg
+    1.45, 1.46, 1.48, 1.40, 1.50, 1.50, // Tl .. Rn
+];
+
+/// Return the single-bond covalent radius (in Angstrom) for an element of
+/// atomic number `number`, or None when the element is out of the tabulated
+/// range or a dummy atom (number 0).
+pub fn covalent_radius(number: usize) -> Option<f64> {
+    if (1..=COVALENT_RADII.len()).contains(&number) {
+        Some(COVALENT_RADII[number - 1])
+    } else {
+        None
+    }
+}
+// covalent radii:1 ends here
+
+// atomic mass
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*atomic mass][atomic mass:1]]
+/// Standard atomic weights (in unified atomic mass units), indexed by
+/// `atomic number - 1`. IUPAC 2021 conventional values; Tc, Pm, Po, At and Rn
+/// have no standard atomic weight and use the mass number of a long-lived
+/// isotope instead.
+const ATOMIC_MASSES: [f64; 86] = [
+    1.008, 4.0026, 6.94, 9.0122, 10.81, 12.011, 14.007, 15.999, 18.998, 20.180, // H .. Ne
+    22.990, 24.305, 26.982, 28.085, 30.974, 32.06, 35.45, 39.948, // Na .. Ar
+    39.098, 40.078, 44.956, 47.867, 50.942, 51.996, 54.938, 55.845, 58.933, 58.693, 63.546,
+    65.38, 69.723, 72.630, 74.922, 78.971, 79.904, 83.798, // K .. Kr
+    85.468, 87.62, 88.906, 91.224, 92.906, 95.95, 98.0, 101.07, 102.91, 106.42, 107.87, 112.41,
+    114.82, 118.71, 121.76, 127.60, 126.90, 131.29, // Rb .. Xe
+    132.91, 137.33, 138.91, 140.12, 140.91, 144.24, 145.0, 150.36, 151.96, 157.25, 158.93,
+    162.50, 164.93, 167.26, 168.93, 173.05, 174.97, // Cs .. Lu
+    178.49, 180.95, 183.84, 186.21, 190.23, 192.22, 195.08, 196.97, 200.59, // Hf .. Hg
+    204.38, 207.2, 208.98, 209.0, 210.0, 222.0, // Tl .. Rn
+];
+
+/// Return the standard atomic weight (in amu) for an element of atomic number
+/// `number`, or None when the element is out of the tabulated range.
+pub fn atomic_mass(number: usize) -> Option<f64> {
+    if (1..=ATOMIC_MASSES.len()).contains(&number) {
+        Some(ATOMIC_MASSES[number - 1])
+    } else {
+        None
+    }
+}
+// atomic mass:1 ends here
@@ -77,10 +77,84 @@ fn test_formula() {
 
 // [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*api][api:1]]
 impl Molecule {
-    /// Return the molecule formula represented in string
-    /// Return empty string if molecule containing no atom
+    /// Return the molecule formula represented in string following the Hill
+    /// system. Return empty string if molecule containing no atom.
     pub fn formula(&self) -> String {
-        get_reduced_formula(self.symbols())
+        self.formula_hill()
+    }
+
+    /// Return the molecular formula in the Hill system.
+    ///
+    /// When carbon is present the output lists C first, then H, then all
+    /// remaining elements in alphabetical order; when carbon is absent every
+    /// element is listed strictly alphabetically, so the result is
+    /// deterministic between runs. Isotope-labeled atoms are emitted in
+    /// brackets (e.g. `[13C]`), and an optional `total_charge` property is
+    /// appended as a signed suffix (e.g. a sulfate ion is emitted as `O4S^2-`,
+    /// since the absence of carbon forces strict alphabetical ordering).
+    pub fn formula_hill(&self) -> String {
+        // count tokens keyed by (element symbol, optional isotope mass number).
+        let mut counts: HashMap<(String, Option<u32>), usize> = HashMap::new();
+        for (sn, atom) in self.atoms() {
+            let key = (atom.symbol().to_string(), self.get_isotope(sn));
+            *counts.entry(key).or_insert(0) += 1;
+        }
+
+        // group the tokens by element, keeping the isotopes of each element
+        // together and ordered (unlabeled first, then by ascending mass).
+        let mut by_element: HashMap<String, Vec<(Option<u32>, usize)>> = HashMap::new();
+        for ((sym, iso), n) in counts {
+            by_element.entry(sym).or_default().push((iso, n));
+        }
+        for variants in by_element.values_mut() {
+            variants.sort_by(|a, b| a.0.cmp(&b.0));
+        }
+
+        // Hill ordering of the element symbols.
+        let mut elements: Vec<String> = by_element.keys().cloned().collect();
+        let has_carbon = by_element.contains_key("C");
+        elements.sort();
+        if has_carbon {
+            elements.retain(|e| e != "C" && e != "H");
+            elements.insert(0, "C".to_string());
+            if by_element.contains_key("H") {
+                elements.insert(1, "H".to_string());
+            }
+        }
+
+        let mut formula = String::new();
+        for element in elements {
+            for (iso, n) in &by_element[&element] {
+                match iso {
+                    Some(mass) => formula.push_str(&format!("[{}{}]", mass, element)),
+                    None => formula.push_str(&element),
+                }
+                if *n > 1 {
+                    formula.push_str(&n.to_string());
+                }
+            }
+        }
+
+        // append an overall charge suffix when a `total_charge` property is set.
+        if let Some(charge) = self.total_charge() {
+            if charge != 0 {
+                let sign = if charge > 0 { '+' } else { '-' };
+                let mag = charge.unsigned_abs();
+                formula.push('^');
+                if mag > 1 {
+                    formula.push_str(&mag.to_string());
+                }
+                formula.push(sign);
+            }
+        }
+
+        formula
+    }
+
+    /// Read the optional overall charge stored under the `total_charge`
+    /// property, if present and integral.
+    fn total_charge(&self) -> Option<i32> {
+        self.properties.load("total_charge").ok()
     }
 
     /// Return a hashmap for counting atom symbols.
@@ -89,3 +163,57 @@ impl Molecule {
     }
 }
 // api:1 ends here
+
+// test
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*test][test:1]]
+#[test]
+fn test_formula_hill() {
+    // carbon present: C, then H, then the rest alphabetically.
+    let ethanol = Molecule::from_atoms(vec![
+        ("C", [0.0, 0.0, 0.0]),
+        ("C", [1.5, 0.0, 0.0]),
+        ("O", [2.0, 1.3, 0.0]),
+        ("H", [0.0, 1.0, 0.0]),
+        ("H", [0.0, -1.0, 0.0]),
+        ("H", [-1.0, 0.0, 0.0]),
+        ("H", [1.5, -1.0, 0.0]),
+        ("H", [1.5, 1.0, 0.0]),
+        ("H", [2.9, 1.3, 0.0]),
+    ]);
+    assert_eq!(ethanol.formula_hill(), "C2H6O");
+
+    // carbon absent: strictly alphabetical, with a charge suffix.
+    let mut sulfate = Molecule::from_atoms(vec![
+        ("S", [0.0, 0.0, 0.0]),
+        ("O", [1.5, 0.0, 0.0]),
+        ("O", [-1.5, 0.0, 0.0]),
+        ("O", [0.0, 1.5, 0.0]),
+        ("O", [0.0, -1.5, 0.0]),
+    ]);
+    sulfate.properties.store("total_charge", -2);
+    assert_eq!(sulfate.formula_hill(), "O4S^2-");
+
+    // isotope label emitted in brackets.
+    let mut methane = Molecule::from_atoms(vec![
+        ("C", [0.0, 0.0, 0.0]),
+        ("H", [0.6, 0.6, 0.6]),
+        ("H", [0.6, -0.6, -0.6]),
+        ("H", [-0.6, 0.6, -0.6]),
+        ("H", [-0.6, -0.6, 0.6]),
+    ]);
+    methane.set_isotope(1, 13);
+    assert_eq!(methane.formula_hill(), "[13C]H4");
+
+    // single positive charge on a carbon-free species.
+    let mut ammonium = Molecule::from_atoms(vec![
+        ("N", [0.0, 0.0, 0.0]),
+        ("H", [0.6, 0.6, 0.6]),
+        ("H", [0.6, -0.6, -0.6]),
+        ("H", [-0.6, 0.6, -0.6]),
+        ("H", [-0.6, -0.6, 0.6]),
+    ]);
+    ammonium.properties.store("total_charge", 1);
+    assert_eq!(ammonium.formula_hill(), "H4N^+");
+}
+// test:1 ends here
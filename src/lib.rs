@@ -21,6 +21,8 @@ mod atom;
 mod bond;
 mod element;
 mod molecule;
+mod residue;
+mod trajectory;
 // mods:1 ends here
 
 // exports
@@ -29,4 +31,6 @@ mod molecule;
 pub use crate::atom::*;
 pub use crate::bond::*;
 pub use crate::molecule::*;
+pub use crate::residue::*;
+pub use crate::trajectory::*;
 // exports:1 ends here
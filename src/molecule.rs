@@ -44,6 +44,25 @@ pub struct Molecule {
 
     /// mapping: Atom serial number <=> graph NodeIndex
     pub(crate) mapping: BiHashMap<usize, NodeIndex>,
+
+    /// Residue/chain annotation for biomolecules, indexed by atom serial
+    /// number and queried through [residues](#method.residues) and friends.
+    /// Deliberately separate from the graph, so a molecule without residue
+    /// data is indistinguishable from one built before this field existed.
+    #[serde(default)]
+    pub(crate) residue_info: std::collections::HashMap<usize, crate::residue::ResidueInfo>,
+
+    /// Atom velocities (Å/fs) for MD and trajectory workflows, looked up by
+    /// serial number through [get_velocity](#method.get_velocity). Empty while
+    /// a structure is purely static.
+    #[serde(default)]
+    pub(crate) velocity_data: std::collections::HashMap<usize, [f64; 3]>,
+
+    /// Isotope mass numbers for atoms that are isotopically labeled, indexed by
+    /// serial number. Consumed by [formula_hill](#method.formula_hill) to emit
+    /// bracketed labels such as `[13C]`; unlabeled atoms have no entry.
+    #[serde(default)]
+    pub(crate) isotope_labels: std::collections::HashMap<usize, u32>,
 }
 
 /// Methods for internal uses
@@ -137,6 +156,11 @@ impl Molecule {
     /// exist.
     pub fn remove_atom(&mut self, a: usize) -> Option<Atom> {
         if let Some(n) = self.remove_atom_sn(a) {
+            // drop orthogonal per-atom annotations so a later atom re-added at
+            // the same serial number does not inherit stale data.
+            self.residue_info.remove(&a);
+            self.velocity_data.remove(&a);
+            self.isotope_labels.remove(&a);
             self.graph.remove_node(n)
         } else {
             None
@@ -401,6 +425,622 @@ impl Molecule {
 }
 // edit:1 ends here
 
+// [[file:../gchemol-core.note::*rebond][rebond:1]]
+use std::collections::HashMap;
+
+/// Default tolerance (in Angstrom) added to the sum of covalent radii when
+/// perceiving bonds.
+const BOND_TOLERANCE: f64 = 0.45;
+
+/// Perceive chemical bonds from interatomic distances.
+impl Molecule {
+    /// Recreate single bonds by comparing each pair's distance against the sum
+    /// of their covalent radii plus a default tolerance (0.45 Å).
+    ///
+    /// File formats such as XYZ carry no connectivity; `rebond` guesses it from
+    /// geometry. Any existing bonds are discarded first. When `self.lattice` is
+    /// set, distances follow the minimum-image convention so periodic
+    /// structures bond correctly across cell boundaries.
+    pub fn rebond(&mut self) {
+        self.rebond_with_tolerance(BOND_TOLERANCE);
+    }
+
+    /// Perceive bonds as [rebond](#method.rebond) does, using a custom distance
+    /// `tol` (in Angstrom) for tuning sensitivity.
+    pub fn rebond_with_tolerance(&mut self, tol: f64) {
+        // collect per-atom data: serial number, covalent radius, position.
+        let atoms: Vec<(usize, f64, Point3)> = self
+            .atoms()
+            .map(|(sn, atom)| (sn, covalent_radius(atom.number()).unwrap_or(0.0), atom.position()))
+            .collect();
+        if atoms.len() < 2 {
+            return;
+        }
+
+        // bin into a uniform grid whose cell size is the largest possible bond
+        // cutoff, so only atoms in the 27 neighboring cells can be bonded.
+        let rmax = atoms.iter().map(|(_, r, _)| *r).fold(0.0, f64::max);
+        let cutoff = 2.0 * rmax + tol;
+        // zero cutoff (no tabulated radius and no positive tolerance): use a
+        // unit cell size so binning does not divide by zero.
+        let cell = if cutoff > 0.0 { cutoff } else { 1.0 };
+
+        // remove stale connectivity before perceiving afresh.
+        let old: Vec<_> = self.bonds().map(|(u, v, _)| (u, v)).collect();
+        for (u, v) in old {
+            self.remove_bond(u, v);
+        }
+
+        // enumerate candidate pairs from the cell list. The periodic path bins
+        // in fractional space and wraps the neighbor indices modulo the per-axis
+        // cell count, so contacts that are short only under the minimum-image
+        // convention (e.g. fractional x≈0.01 and x≈0.99) are still enumerated.
+        let bonded = match &self.lattice {
+            Some(lat) => {
+                let lengths = lat.lengths();
+                let ncell: [i64; 3] = [
+                    (lengths[0] / cell).floor().max(1.0) as i64,
+                    (lengths[1] / cell).floor().max(1.0) as i64,
+                    (lengths[2] / cell).floor().max(1.0) as i64,
+                ];
+                // fractional coordinates folded into [0, 1).
+                let fracs: Vec<[f64; 3]> = atoms
+                    .iter()
+                    .map(|(_, _, p)| {
+                        let mut f = lat.to_frac(*p);
+                        for x in f.iter_mut() {
+                            *x -= x.floor();
+                        }
+                        f
+                    })
+                    .collect();
+                let wrap = |i: i64, n: i64| ((i % n) + n) % n;
+                let bin = |f: &[f64; 3]| -> (i64, i64, i64) {
+                    (
+                        wrap((f[0] * ncell[0] as f64).floor() as i64, ncell[0]),
+                        wrap((f[1] * ncell[1] as f64).floor() as i64, ncell[1]),
+                        wrap((f[2] * ncell[2] as f64).floor() as i64, ncell[2]),
+                    )
+                };
+                let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+                for (i, f) in fracs.iter().enumerate() {
+                    grid.entry(bin(f)).or_default().push(i);
+                }
+                // a small cell count makes wrapped neighbor cells repeat, so
+                // collect unordered candidate pairs into a set to dedup them.
+                let mut seen = std::collections::HashSet::new();
+                for i in 0..atoms.len() {
+                    let (cx, cy, cz) = bin(&fracs[i]);
+                    for dx in -1..=1 {
+                        for dy in -1..=1 {
+                            for dz in -1..=1 {
+                                let key = (
+                                    wrap(cx + dx, ncell[0]),
+                                    wrap(cy + dy, ncell[1]),
+                                    wrap(cz + dz, ncell[2]),
+                                );
+                                if let Some(js) = grid.get(&key) {
+                                    for &j in js {
+                                        if j > i {
+                                            seen.insert((i, j));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                // keep the candidate pairs whose minimum-image distance is
+                // within the covalent-radius cutoff.
+                seen.into_iter()
+                    .filter(|&(i, j)| {
+                        let d = lat.distance(atoms[i].2, atoms[j].2);
+                        d > 1e-3 && d < atoms[i].1 + atoms[j].1 + tol
+                    })
+                    .map(|(i, j)| (atoms[i].0, atoms[j].0, Bond::single()))
+                    .collect::<Vec<_>>()
+            }
+            None => {
+                let bin = |p: &Point3| -> (i64, i64, i64) {
+                    (
+                        (p[0] / cell).floor() as i64,
+                        (p[1] / cell).floor() as i64,
+                        (p[2] / cell).floor() as i64,
+                    )
+                };
+                let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+                for (i, (_, _, p)) in atoms.iter().enumerate() {
+                    grid.entry(bin(p)).or_default().push(i);
+                }
+                let mut new_bonds = Vec::new();
+                for (i, (sni, ri, pi)) in atoms.iter().enumerate() {
+                    let (cx, cy, cz) = bin(pi);
+                    for dx in -1..=1 {
+                        for dy in -1..=1 {
+                            for dz in -1..=1 {
+                                if let Some(js) = grid.get(&(cx + dx, cy + dy, cz + dz)) {
+                                    for &j in js {
+                                        if j <= i {
+                                            continue;
+                                        }
+                                        let (snj, rj, pj) = &atoms[j];
+                                        let d = euclidean_distance(*pi, *pj);
+                                        if d > 1e-3 && d < ri + rj + tol {
+                                            new_bonds.push((*sni, *snj, Bond::single()));
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                new_bonds
+            }
+        };
+        self.add_bonds_from(bonded);
+    }
+}
+
+fn euclidean_distance(pi: Point3, pj: Point3) -> f64 {
+    let dx = pi[0] - pj[0];
+    let dy = pi[1] - pj[1];
+    let dz = pi[2] - pj[2];
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+// rebond:1 ends here
+
+// [[file:../gchemol-core.note::*fragments][fragments:1]]
+/// Decompose a molecule into its chemically disconnected pieces.
+impl Molecule {
+    /// Group atom serial numbers into connected components following the bond
+    /// graph. Each inner `Vec` holds the serial numbers of one connected
+    /// fragment, sorted ascending. Cheaper than [fragments](#method.fragments)
+    /// for callers that only need membership, as nothing is cloned.
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        let mut visited = std::collections::HashSet::new();
+        let mut components = Vec::new();
+        for start in self.serial_numbers() {
+            if visited.contains(&start) {
+                continue;
+            }
+            // breadth-first walk over the bond edges from `start`.
+            let mut group = Vec::new();
+            let mut queue = std::collections::VecDeque::new();
+            queue.push_back(start);
+            visited.insert(start);
+            while let Some(sn) = queue.pop_front() {
+                group.push(sn);
+                let n = self.node_index(sn);
+                for nbr in self.graph.neighbors(n) {
+                    let m = self.atom_sn(nbr);
+                    if visited.insert(m) {
+                        queue.push_back(m);
+                    }
+                }
+            }
+            group.sort_unstable();
+            components.push(group);
+        }
+        components
+    }
+
+    /// Split the molecule into a new `Molecule` per connected component,
+    /// preserving original serial numbers, atom properties, and a copy of
+    /// `self.lattice`. This pairs naturally with [rebond](#method.rebond) for
+    /// counting or isolating molecular entities (e.g. solvent around a solute).
+    pub fn fragments(&self) -> impl Iterator<Item = Molecule> + '_ {
+        self.connected_components().into_iter().map(move |group| {
+            let members: std::collections::HashSet<usize> = group.iter().copied().collect();
+            let mut mol = Molecule::default();
+            mol.lattice = self.lattice.clone();
+            for &sn in &group {
+                mol.add_atom(sn, self.graph[self.node_index(sn)].clone());
+                // carry over the orthogonal per-atom annotations so a sliced
+                // binding-site or ligand keeps its residue/velocity/isotope data.
+                if let Some(info) = self.residue_info.get(&sn) {
+                    mol.residue_info.insert(sn, info.clone());
+                }
+                if let Some(v) = self.velocity_data.get(&sn) {
+                    mol.velocity_data.insert(sn, *v);
+                }
+                if let Some(iso) = self.isotope_labels.get(&sn) {
+                    mol.isotope_labels.insert(sn, *iso);
+                }
+            }
+            // keep only the bonds internal to this fragment.
+            for (u, v, b) in self.bonds() {
+                if members.contains(&u) && members.contains(&v) {
+                    mol.add_bond(u, v, b.clone());
+                }
+            }
+            mol
+        })
+    }
+}
+// fragments:1 ends here
+
+// [[file:../gchemol-core.note::*symmetry][symmetry:1]]
+use nalgebra::{Matrix3, Vector3};
+
+type M3 = Matrix3<f64>;
+type V3 = Vector3<f64>;
+
+/// Molecular symmetry analysis.
+impl Molecule {
+    /// Determine the molecular point group from its geometry and return the
+    /// Schoenflies symbol (e.g. `C2v`, `D6h`, `Td`).
+    ///
+    /// Atoms are translated to the center of mass and the moment-of-inertia
+    /// tensor is diagonalized: a near-zero smallest moment marks a linear
+    /// molecule, and the principal axes join the candidate symmetry axes. The
+    /// spherical/symmetric/asymmetric top type is not determined; the group
+    /// follows from the accepted operations alone. Candidate symmetry
+    /// operations (inversion, proper/improper axes, mirror planes) are accepted
+    /// only when they map every atom onto another atom of the same element
+    /// within `tol`. Choose `tol` well below the shortest bond length to stay
+    /// robust against numerical noise.
+    pub fn point_group(&self, tol: f64) -> String {
+        let elements: Vec<usize> = self.atomic_numbers().collect();
+        let n = elements.len();
+        if n == 0 {
+            return "C1".to_string();
+        }
+        if n == 1 {
+            // a single atom has no geometry to fix an orientation; report the
+            // trivial group rather than claiming spherical symmetry.
+            return "C1".to_string();
+        }
+
+        // translate to the center of mass.
+        let masses: Vec<f64> = elements.iter().map(|&z| atomic_mass(z).unwrap_or(1.0)).collect();
+        let total: f64 = masses.iter().sum();
+        let mut com = V3::zeros();
+        for ((_, atom), m) in self.atoms().zip(masses.iter()) {
+            com += *m * point_to_vec(atom.position());
+        }
+        com /= total;
+        let coords: Vec<V3> = self.positions().map(|p| point_to_vec(p) - com).collect();
+
+        // moment-of-inertia tensor and its principal moments/axes.
+        let mut inertia = M3::zeros();
+        for (r, m) in coords.iter().zip(masses.iter()) {
+            let r2 = r.dot(r);
+            inertia += *m * (M3::identity() * r2 - r * r.transpose());
+        }
+        let eig = inertia.symmetric_eigen();
+        let mut idx = [0usize, 1, 2];
+        idx.sort_by(|&a, &b| eig.eigenvalues[a].total_cmp(&eig.eigenvalues[b]));
+        let moments: Vec<f64> = idx.iter().map(|&i| eig.eigenvalues[i]).collect();
+        let axes: Vec<V3> = idx.iter().map(|&i| eig.eigenvectors.column(i).into()).collect();
+
+        let accept = |op: &M3| self.operation_maps(&coords, &elements, op, tol);
+
+        // linear molecule: one near-zero moment.
+        let scale = moments[2].max(1.0);
+        if moments[0] / scale < 1e-3 {
+            return if accept(&(-M3::identity())) {
+                "D\u{221e}h".to_string()
+            } else {
+                "C\u{221e}v".to_string()
+            };
+        }
+
+        // collect candidate axis directions: principal axes, atom directions,
+        // and atom-pair midpoints/differences.
+        let mut candidates: Vec<V3> = axes.clone();
+        for c in &coords {
+            push_axis(&mut candidates, *c);
+        }
+        for i in 0..n {
+            for j in (i + 1)..n {
+                push_axis(&mut candidates, coords[i] + coords[j]);
+                push_axis(&mut candidates, coords[i] - coords[j]);
+            }
+        }
+
+        // highest proper rotation order on each candidate axis.
+        let mut n_max = 1usize;
+        let mut principal = axes[2];
+        let mut proper_axes: Vec<(V3, usize)> = Vec::new();
+        for axis in &candidates {
+            let mut best = 1;
+            for order in 2..=8 {
+                if accept(&rotation(axis, 2.0 * std::f64::consts::PI / order as f64)) {
+                    best = order;
+                }
+            }
+            if best > 1 {
+                proper_axes.push((*axis, best));
+                if best > n_max {
+                    n_max = best;
+                    principal = *axis;
+                }
+            }
+        }
+
+        let has_i = accept(&(-M3::identity()));
+
+        // no proper rotation axis: Cs / Ci / C1.
+        if n_max == 1 {
+            if candidates.iter().any(|a| accept(&reflection(a))) {
+                return "Cs".to_string();
+            }
+            if has_i {
+                return "Ci".to_string();
+            }
+            return "C1".to_string();
+        }
+
+        // cubic / icosahedral: more than one high-order (n >= 3) proper axis.
+        let high = proper_axes.iter().filter(|(_, o)| *o >= 3).count();
+        if high >= 2 {
+            let has_c5 = proper_axes.iter().any(|(_, o)| *o == 5);
+            let has_c4 = proper_axes.iter().any(|(_, o)| *o == 4);
+            let has_mirror = candidates.iter().any(|a| accept(&reflection(a)));
+            if has_c5 {
+                return if has_i { "Ih" } else { "I" }.to_string();
+            }
+            if has_c4 {
+                return if has_i { "Oh" } else { "O" }.to_string();
+            }
+            return if has_i {
+                "Th"
+            } else if has_mirror {
+                "Td"
+            } else {
+                "T"
+            }
+            .to_string();
+        }
+
+        // single principal axis: C / D family.
+        let perp_c2 = proper_axes
+            .iter()
+            .filter(|(a, o)| *o >= 2 && a.cross(&principal).norm() > 1e-3 && accept(&rotation(a, std::f64::consts::PI)))
+            .count();
+        let sigma_h = accept(&reflection(&principal));
+        let sigma_v = candidates
+            .iter()
+            .filter(|a| a.cross(&principal).norm() > 1e-3 && accept(&reflection(a)))
+            .count();
+        let has_s2n = accept(&improper(&principal, std::f64::consts::PI / n_max as f64));
+
+        if perp_c2 >= n_max {
+            if sigma_h {
+                return format!("D{}h", n_max);
+            }
+            if sigma_v >= n_max {
+                return format!("D{}d", n_max);
+            }
+            return format!("D{}", n_max);
+        }
+        if sigma_h {
+            return format!("C{}h", n_max);
+        }
+        if sigma_v >= 1 {
+            return format!("C{}v", n_max);
+        }
+        if has_s2n {
+            return format!("S{}", 2 * n_max);
+        }
+        format!("C{}", n_max)
+    }
+
+    /// Return true if `op` (a 3×3 matrix acting on centered coordinates) maps
+    /// every atom onto an atom of the same element within `tol`.
+    fn operation_maps(&self, coords: &[V3], elements: &[usize], op: &M3, tol: f64) -> bool {
+        for (i, r) in coords.iter().enumerate() {
+            let t = op * r;
+            let matched = coords.iter().enumerate().any(|(j, s)| {
+                elements[j] == elements[i] && (t - s).norm() < tol
+            });
+            if !matched {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn point_to_vec(p: Point3) -> V3 {
+    V3::new(p[0], p[1], p[2])
+}
+
+/// Append a normalized axis direction unless it (or its negative) is already
+/// present or too short to define a direction.
+fn push_axis(axes: &mut Vec<V3>, v: V3) {
+    let norm = v.norm();
+    if norm < 1e-6 {
+        return;
+    }
+    let u = v / norm;
+    if axes.iter().any(|a| (a - u).norm() < 1e-4 || (a + u).norm() < 1e-4) {
+        return;
+    }
+    axes.push(u);
+}
+
+/// Rotation matrix of `angle` radians about a (not necessarily unit) `axis`.
+fn rotation(axis: &V3, angle: f64) -> M3 {
+    let u = axis.normalize();
+    let (s, c) = angle.sin_cos();
+    let k = M3::new(0.0, -u[2], u[1], u[2], 0.0, -u[0], -u[1], u[0], 0.0);
+    M3::identity() + s * k + (1.0 - c) * k * k
+}
+
+/// Reflection matrix through the plane with the given `normal`.
+fn reflection(normal: &V3) -> M3 {
+    let u = normal.normalize();
+    M3::identity() - 2.0 * u * u.transpose()
+}
+
+/// Improper rotation S_n: rotation about `axis` followed by reflection in the
+/// perpendicular plane.
+fn improper(axis: &V3, angle: f64) -> M3 {
+    reflection(axis) * rotation(axis, angle)
+}
+// symmetry:1 ends here
+
+// [[file:../gchemol-core.note::*transform][transform:1]]
+/// Rigid-body transforms and structural superposition.
+impl Molecule {
+    /// Translate all atoms by vector `v`, honoring frozen coordinates the same
+    /// way [update_positions](#method.update_positions) does.
+    pub fn translate<V: Into<Vector3f>>(&mut self, v: V) {
+        let v = v.into();
+        let new: Vec<[f64; 3]> = self
+            .positions()
+            .map(|p| [p[0] + v[0], p[1] + v[1], p[2] + v[2]])
+            .collect();
+        self.update_positions(new);
+    }
+
+    /// Rotate all atoms about the origin by the 3×3 matrix `mat` (row-major),
+    /// honoring frozen coordinates.
+    pub fn rotate(&mut self, mat: [[f64; 3]; 3]) {
+        let new: Vec<[f64; 3]> = self.positions().map(|p| mat_mul_vec(&mat, p)).collect();
+        self.update_positions(new);
+    }
+
+    /// Apply a rigid transformation: rotate by `rot` (row-major 3×3) then
+    /// translate by `trans`, honoring frozen coordinates.
+    pub fn apply_transformation<V: Into<Vector3f>>(&mut self, rot: [[f64; 3]; 3], trans: V) {
+        let t = trans.into();
+        let new: Vec<[f64; 3]> = self
+            .positions()
+            .map(|p| {
+                let r = mat_mul_vec(&rot, p);
+                [r[0] + t[0], r[1] + t[1], r[2] + t[2]]
+            })
+            .collect();
+        self.update_positions(new);
+    }
+
+    /// Superpose `self` onto `reference` using the Kabsch algorithm over atoms
+    /// matched by serial number, and return the resulting RMSD.
+    ///
+    /// Both coordinate sets are centered on their centroids, the optimal
+    /// rotation is obtained from the SVD of the covariance matrix (with a
+    /// reflection correction), and `self` is moved onto `reference` in place.
+    /// Returns an error when the two molecules differ in atom count.
+    pub fn superpose(&mut self, reference: &Molecule) -> Result<f64> {
+        if self.natoms() != reference.natoms() {
+            bail!(
+                "cannot superpose molecules of different sizes: {} vs {}",
+                self.natoms(),
+                reference.natoms()
+            );
+        }
+
+        // collect matched coordinate pairs (P = self, Q = reference).
+        let sns: Vec<usize> = self.serial_numbers().collect();
+        let mut p = Vec::with_capacity(sns.len());
+        let mut q = Vec::with_capacity(sns.len());
+        for &sn in &sns {
+            let a = self.get_atom(sn).expect("missing atom");
+            let b = reference
+                .get_atom(sn)
+                .ok_or_else(|| format_err!("reference has no atom with serial number {}", sn))?;
+            p.push(point_to_vec(a.position()));
+            q.push(point_to_vec(b.position()));
+        }
+
+        let np = p.len() as f64;
+        let cp: V3 = p.iter().sum::<V3>() / np;
+        let cq: V3 = q.iter().sum::<V3>() / np;
+
+        // covariance matrix H = Pᵀ·Q over centered coordinates.
+        let mut h = M3::zeros();
+        for (pi, qi) in p.iter().zip(q.iter()) {
+            h += (pi - cp) * (qi - cq).transpose();
+        }
+        let svd = h.svd(true, true);
+        let u = svd.u.unwrap();
+        let vt = svd.v_t.unwrap();
+        let v = vt.transpose();
+        // correct for a possible reflection so that det(R) = +1.
+        let d = (v * u.transpose()).determinant().signum();
+        let r = v * M3::from_diagonal(&V3::new(1.0, 1.0, d)) * u.transpose();
+
+        // apply the rotation and centroid translation to `self`.
+        let new: Vec<[f64; 3]> = p
+            .iter()
+            .map(|pi| {
+                let x = r * (pi - cp) + cq;
+                [x[0], x[1], x[2]]
+            })
+            .collect();
+        self.update_positions(new);
+
+        // RMSD after alignment.
+        let mut sd = 0.0;
+        for (pi, qi) in p.iter().zip(q.iter()) {
+            let x = r * (pi - cp) + cq;
+            sd += (x - qi).norm_squared();
+        }
+        Ok((sd / np).sqrt())
+    }
+}
+
+fn mat_mul_vec(m: &[[f64; 3]; 3], v: Point3) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+// transform:1 ends here
+
+// [[file:../gchemol-core.note::*velocities][velocities:1]]
+/// Per-atom velocities, mirroring the position accessors.
+impl Molecule {
+    /// Read access to the velocity of atom `sn`, if one has been set.
+    pub fn get_velocity(&self, sn: usize) -> Option<[f64; 3]> {
+        self.velocity_data.get(&sn).copied()
+    }
+
+    /// Set the velocity of atom `sn`.
+    pub fn set_velocity<V: Into<Vector3f>>(&mut self, sn: usize, v: V) {
+        let v = v.into();
+        self.velocity_data.insert(sn, [v[0], v[1], v[2]]);
+    }
+
+    /// Iterate over atom velocities ordered by serial numbers. Atoms without a
+    /// velocity yield a zero vector.
+    pub fn velocities(&self) -> impl Iterator<Item = [f64; 3]> + '_ {
+        self.serial_numbers()
+            .map(move |sn| self.velocity_data.get(&sn).copied().unwrap_or([0.0; 3]))
+    }
+
+    /// Set velocities of atoms in sequential order.
+    pub fn set_velocities<T, V>(&mut self, velocities: T)
+    where
+        T: IntoIterator<Item = V>,
+        V: Into<Vector3f>,
+    {
+        let sns: Vec<usize> = self.serial_numbers().collect();
+        for (sn, v) in sns.into_iter().zip(velocities.into_iter()) {
+            self.set_velocity(sn, v);
+        }
+    }
+}
+// velocities:1 ends here
+
+// [[file:../gchemol-core.note::*isotope][isotope:1]]
+/// Per-atom isotope labels.
+impl Molecule {
+    /// Label atom `sn` with an isotope `mass` number (e.g. 13 for ¹³C).
+    pub fn set_isotope(&mut self, sn: usize, mass: u32) {
+        self.isotope_labels.insert(sn, mass);
+    }
+
+    /// Read the isotope mass number of atom `sn`, if one has been set.
+    pub fn get_isotope(&self, sn: usize) -> Option<u32> {
+        self.isotope_labels.get(&sn).copied()
+    }
+}
+// isotope:1 ends here
+
 // [[file:../gchemol-core.note::*test][test:1]]
 #[test]
 fn test() {
@@ -426,3 +1066,132 @@ fn test() {
     mol.set_title(format!("Molecule: {}", 4));
 }
 // test:1 ends here
+
+// [[file:../gchemol-core.note::*test rebond][test rebond:1]]
+#[test]
+fn test_rebond() {
+    // two hydrogens at a bonding distance plus a distant one: one bond only.
+    let mut mol = Molecule::from_atoms(vec![
+        ("H", [0.0, 0.0, 0.0]),
+        ("H", [0.0, 0.0, 0.74]),
+        ("H", [0.0, 0.0, 5.0]),
+    ]);
+    mol.rebond();
+    assert_eq!(mol.nbonds(), 1);
+    assert!(mol.get_bond(1, 2).is_some());
+
+    // periodic case: two atoms straddling a cell boundary bond across it.
+    let mut mol = Molecule::from_atoms(vec![("H", [0.1, 0.0, 0.0]), ("H", [9.9, 0.0, 0.0])]);
+    mol.lattice = Some(Lattice::new([[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]]));
+    mol.rebond();
+    assert_eq!(mol.nbonds(), 1);
+}
+// test rebond:1 ends here
+
+// [[file:../gchemol-core.note::*test fragments][test fragments:1]]
+#[test]
+fn test_fragments() {
+    let mut mol = Molecule::from_atoms(vec![
+        ("O", [0.0, 0.0, 0.0]),
+        ("H", [0.0, 0.0, 1.0]),
+        ("O", [5.0, 0.0, 0.0]),
+        ("H", [5.0, 0.0, 1.0]),
+    ]);
+    mol.add_bond(1, 2, Bond::single());
+    mol.add_bond(3, 4, Bond::single());
+
+    let comps = mol.connected_components();
+    assert_eq!(comps, vec![vec![1, 2], vec![3, 4]]);
+
+    let frags: Vec<_> = mol.fragments().collect();
+    assert_eq!(frags.len(), 2);
+    for frag in &frags {
+        assert_eq!(frag.natoms(), 2);
+        assert_eq!(frag.nbonds(), 1);
+    }
+    // original serial numbers are preserved.
+    assert!(frags[1].get_atom(3).is_some());
+    assert!(frags[1].get_atom(4).is_some());
+}
+// test fragments:1 ends here
+
+// [[file:../gchemol-core.note::*test point group][test point group:1]]
+#[test]
+fn test_point_group() {
+    let water = Molecule::from_atoms(vec![
+        ("O", [0.0, 0.0, 0.0]),
+        ("H", [0.757, 0.586, 0.0]),
+        ("H", [-0.757, 0.586, 0.0]),
+    ]);
+    assert_eq!(water.point_group(0.1), "C2v");
+
+    let ammonia = Molecule::from_atoms(vec![
+        ("N", [0.0, 0.0, 0.0]),
+        ("H", [0.940, 0.0, -0.33]),
+        ("H", [-0.470, 0.814, -0.33]),
+        ("H", [-0.470, -0.814, -0.33]),
+    ]);
+    assert_eq!(ammonia.point_group(0.1), "C3v");
+
+    let d = 0.629;
+    let methane = Molecule::from_atoms(vec![
+        ("C", [0.0, 0.0, 0.0]),
+        ("H", [d, d, d]),
+        ("H", [d, -d, -d]),
+        ("H", [-d, d, -d]),
+        ("H", [-d, -d, d]),
+    ]);
+    assert_eq!(methane.point_group(0.1), "Td");
+
+    // benzene ring in the xy-plane.
+    let rc = 1.39;
+    let rh = 2.46;
+    let mut atoms = Vec::new();
+    for k in 0..6 {
+        let a = std::f64::consts::PI / 3.0 * k as f64;
+        atoms.push(("C", [rc * a.cos(), rc * a.sin(), 0.0]));
+    }
+    for k in 0..6 {
+        let a = std::f64::consts::PI / 3.0 * k as f64;
+        atoms.push(("H", [rh * a.cos(), rh * a.sin(), 0.0]));
+    }
+    let benzene = Molecule::from_atoms(atoms);
+    assert_eq!(benzene.point_group(0.1), "D6h");
+
+    // heteronuclear diatomic: C∞v; homonuclear: D∞h.
+    let co = Molecule::from_atoms(vec![("C", [0.0, 0.0, 0.0]), ("O", [0.0, 0.0, 1.13])]);
+    assert_eq!(co.point_group(0.1), "C\u{221e}v");
+    let n2 = Molecule::from_atoms(vec![("N", [0.0, 0.0, 0.0]), ("N", [0.0, 0.0, 1.10])]);
+    assert_eq!(n2.point_group(0.1), "D\u{221e}h");
+}
+// test point group:1 ends here
+
+// [[file:../gchemol-core.note::*test superpose][test superpose:1]]
+#[test]
+fn test_superpose() {
+    let reference = Molecule::from_atoms(vec![
+        ("C", [0.0, 0.0, 0.0]),
+        ("O", [1.2, 0.0, 0.0]),
+        ("H", [-0.5, 0.9, 0.0]),
+        ("H", [-0.5, -0.9, 0.3]),
+    ]);
+
+    // displace a copy by a known rotation (90° about z) and translation.
+    let mut mol = reference.clone();
+    mol.rotate([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+    mol.translate([1.0, 2.0, 3.0]);
+
+    // Kabsch should recover the original within numerical noise.
+    let rmsd = mol.superpose(&reference).expect("superpose failed");
+    assert!(rmsd < 1e-6, "rmsd = {}", rmsd);
+    for (sn, atom) in reference.atoms() {
+        let p = mol.get_atom(sn).unwrap().position();
+        let q = atom.position();
+        assert!(euclidean_distance(p, q) < 1e-6);
+    }
+
+    // size mismatch is an error.
+    let small = Molecule::from_atoms(vec![("C", [0.0, 0.0, 0.0])]);
+    assert!(mol.superpose(&small).is_err());
+}
+// test superpose:1 ends here
@@ -0,0 +1,131 @@
+// imports
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*imports][imports:1]]
+use serde::*;
+
+use std::collections::BTreeMap;
+
+use crate::molecule::Molecule;
+// imports:1 ends here
+
+// base
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*base][base:1]]
+/// Residue/chain annotation attached to an atom, mirroring the
+/// Chain→Residue hierarchy used by biomolecular formats (PDB/mmCIF).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ResidueInfo {
+    /// Residue name, e.g. "ALA" or "HOH".
+    pub residue_name: String,
+    /// Residue sequence number within the chain.
+    pub residue_seq: i64,
+    /// Chain identifier, e.g. "A".
+    pub chain_id: String,
+    /// Insertion code for disambiguating residues sharing a sequence number.
+    pub insertion_code: Option<char>,
+}
+
+/// Grouping key identifying a unique residue: (chain id, residue sequence
+/// number, insertion code).
+pub type ResidueKey = (String, i64, Option<char>);
+
+impl ResidueInfo {
+    /// The grouping key for this residue.
+    fn key(&self) -> ResidueKey {
+        (self.chain_id.clone(), self.residue_seq, self.insertion_code)
+    }
+}
+// base:1 ends here
+
+// api
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*api][api:1]]
+/// Residue and chain annotation layer for biomolecules.
+impl Molecule {
+    /// Annotate atom `sn` with residue/chain metadata.
+    pub fn set_residue(&mut self, sn: usize, info: ResidueInfo) {
+        self.residue_info.insert(sn, info);
+    }
+
+    /// Read the residue/chain annotation of atom `sn`, if any.
+    pub fn get_residue(&self, sn: usize) -> Option<&ResidueInfo> {
+        self.residue_info.get(&sn)
+    }
+
+    /// Group atom serial numbers by residue, keyed by (chain, residue seq,
+    /// insertion code). Atoms without annotation are skipped. Serial numbers in
+    /// each group are sorted ascending.
+    pub fn residues(&self) -> impl Iterator<Item = (ResidueKey, Vec<usize>)> {
+        let mut groups: BTreeMap<ResidueKey, Vec<usize>> = BTreeMap::new();
+        for (&sn, info) in &self.residue_info {
+            groups.entry(info.key()).or_default().push(sn);
+        }
+        groups.into_iter().map(|(k, mut v)| {
+            v.sort_unstable();
+            (k, v)
+        })
+    }
+
+    /// Group atom serial numbers by chain identifier. Atoms without annotation
+    /// are skipped.
+    pub fn chains(&self) -> impl Iterator<Item = (String, Vec<usize>)> {
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for (&sn, info) in &self.residue_info {
+            groups.entry(info.chain_id.clone()).or_default().push(sn);
+        }
+        groups.into_iter().map(|(k, mut v)| {
+            v.sort_unstable();
+            (k, v)
+        })
+    }
+
+    /// Return the serial numbers of the atoms in residue (`chain`, `seq`),
+    /// sorted ascending, so a binding-site or ligand subset can be fed to the
+    /// fragment/transform APIs.
+    pub fn select_residue(&self, chain: &str, seq: i64) -> Vec<usize> {
+        let mut sns: Vec<usize> = self
+            .residue_info
+            .iter()
+            .filter(|(_, info)| info.chain_id == chain && info.residue_seq == seq)
+            .map(|(&sn, _)| sn)
+            .collect();
+        sns.sort_unstable();
+        sns
+    }
+}
+// api:1 ends here
+
+// test
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*test][test:1]]
+#[test]
+fn test_residues() {
+    use crate::Atom;
+
+    let mut mol = Molecule::default();
+    for i in 1..=4 {
+        mol.add_atom(i, Atom::default());
+    }
+    let info = |name: &str, seq, chain: &str| ResidueInfo {
+        residue_name: name.to_string(),
+        residue_seq: seq,
+        chain_id: chain.to_string(),
+        insertion_code: None,
+    };
+    mol.set_residue(1, info("ALA", 1, "A"));
+    mol.set_residue(2, info("ALA", 1, "A"));
+    mol.set_residue(3, info("GLY", 2, "A"));
+    mol.set_residue(4, info("HOH", 1, "B"));
+
+    let residues: Vec<_> = mol.residues().collect();
+    assert_eq!(residues.len(), 3);
+    assert_eq!(residues[0], (("A".to_string(), 1, None), vec![1, 2]));
+
+    let chains: Vec<_> = mol.chains().collect();
+    assert_eq!(chains.len(), 2);
+    assert_eq!(chains[0], ("A".to_string(), vec![1, 2, 3]));
+
+    assert_eq!(mol.select_residue("A", 1), vec![1, 2]);
+    assert_eq!(mol.get_residue(3).unwrap().residue_name, "GLY");
+}
+// test:1 ends here
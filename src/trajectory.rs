@@ -0,0 +1,121 @@
+// imports
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*imports][imports:1]]
+use serde::*;
+
+use crate::molecule::Molecule;
+// imports:1 ends here
+
+// base
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*base][base:1]]
+/// A sequence of `Molecule` frames, such as a relaxation path or an MD run.
+///
+/// Each frame is stored as a full `Molecule`; the container does not deduplicate
+/// topology between frames. Frames are nonetheless expected to describe the same
+/// atoms so that geometry and velocities can be compared across them. Because
+/// `Molecule` derives `Serialize`/`Deserialize`, a whole `Trajectory` round-trips
+/// through serde as one object.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Trajectory {
+    frames: Vec<Molecule>,
+}
+// base:1 ends here
+
+// api
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*api][api:1]]
+impl Trajectory {
+    /// Create an empty trajectory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a frame to the end of the trajectory.
+    pub fn push(&mut self, mol: Molecule) {
+        self.frames.push(mol);
+    }
+
+    /// Return the number of frames.
+    pub fn nframes(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Read access to frame `i`, or None when out of range.
+    pub fn frame(&self, i: usize) -> Option<&Molecule> {
+        self.frames.get(i)
+    }
+
+    /// Iterate over the frames in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Molecule> {
+        self.frames.iter()
+    }
+
+    /// Compute the per-atom mean-squared displacement (in Å²) relative to the
+    /// first frame, averaged over the subsequent frames that have the same
+    /// number of atoms as the first; other frames are skipped. Atoms are matched
+    /// by position order within each frame; returns an empty vector when the
+    /// trajectory has fewer than two frames or no later frame matches.
+    pub fn mean_squared_displacement(&self) -> Vec<f64> {
+        if self.frames.len() < 2 {
+            return Vec::new();
+        }
+        let reference: Vec<_> = self.frames[0].positions().collect();
+        let natoms = reference.len();
+        let mut msd = vec![0.0; natoms];
+        // only frames whose atom count matches the reference contribute, so a
+        // frame with missing atoms cannot silently skew the average.
+        let mut counted = 0usize;
+        for frame in &self.frames[1..] {
+            if frame.natoms() != natoms {
+                continue;
+            }
+            for (i, p) in frame.positions().enumerate() {
+                let r0 = reference[i];
+                let d = [p[0] - r0[0], p[1] - r0[1], p[2] - r0[2]];
+                msd[i] += d[0] * d[0] + d[1] * d[1] + d[2] * d[2];
+            }
+            counted += 1;
+        }
+        if counted == 0 {
+            return Vec::new();
+        }
+        for x in msd.iter_mut() {
+            *x /= counted as f64;
+        }
+        msd
+    }
+}
+
+impl From<Vec<Molecule>> for Trajectory {
+    fn from(frames: Vec<Molecule>) -> Self {
+        Self { frames }
+    }
+}
+// api:1 ends here
+
+// test
+
+// [[file:~/Workspace/Programming/gchemol-rs/gchemol-core/gchemol-core.note::*test][test:1]]
+#[test]
+fn test_trajectory_msd() {
+    let frame0 = Molecule::from_atoms(vec![("H", [0.0, 0.0, 0.0]), ("H", [1.0, 0.0, 0.0])]);
+    // displace the first atom by 2 Å along x, leave the second in place.
+    let frame1 = Molecule::from_atoms(vec![("H", [2.0, 0.0, 0.0]), ("H", [1.0, 0.0, 0.0])]);
+
+    let mut traj = Trajectory::new();
+    traj.push(frame0);
+    traj.push(frame1);
+    assert_eq!(traj.nframes(), 2);
+
+    let msd = traj.mean_squared_displacement();
+    assert_eq!(msd.len(), 2);
+    assert!((msd[0] - 4.0).abs() < 1e-12);
+    assert!(msd[1].abs() < 1e-12);
+
+    // a frame with a different atom count is ignored rather than undercounting.
+    traj.push(Molecule::from_atoms(vec![("H", [9.0, 0.0, 0.0])]));
+    let msd = traj.mean_squared_displacement();
+    assert!((msd[0] - 4.0).abs() < 1e-12);
+}
+// test:1 ends here